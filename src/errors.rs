@@ -20,6 +20,8 @@ pub enum ParseError {
 	UnitOutOfSequence(usize),
 	#[error("Year or month supplied without a date, and without being allowed to default to now")]
 	InconstantUnitWithoutDate,
+	#[error("Year or month supplied with a fraction, which can not be resolved to a constant duration")]
+	InconstantUnitWithFraction,
 	#[error("During some step in adjusting years or months, the date became out of range")]
 	DateOutOfRange,
 	#[error("Some operation overflowed or some number conversion failed")]
@@ -31,14 +33,12 @@ impl ParseError {
 		bytes: &ParseBytes,
 		units: &[time_units::TimeUnit],
 		unit_cursor: usize,
-		allow_inconstant: bool,
 	) -> Self {
 		let position = bytes.offset();
 		match units[0..unit_cursor]
 			.iter()
 			.position(|unit| bytes.clone().parse_regex(&unit.regex))
 		{
-			Some(0..=1) if !allow_inconstant => Self::InconstantUnitWithoutDate,
 			Some(_) => Self::UnitOutOfSequence(position),
 			None => Self::NoUnit(position),
 		}
@@ -0,0 +1,157 @@
+use std::fmt;
+
+use chrono::Duration;
+
+use crate::Interval;
+
+const WEEK_SECONDS: i64 = 7 * 24 * 60 * 60;
+const DAY_SECONDS: i64 = 24 * 60 * 60;
+const HOUR_SECONDS: i64 = 60 * 60;
+const MINUTE_SECONDS: i64 = 60;
+
+impl Interval {
+	/// Decompose a [`Duration`] into an [`Interval`], greedily filling weeks down to seconds, with
+	/// any sub-second remainder kept in `nanoseconds`.
+	///
+	/// Years and months are left at zero, since a bare duration has no calendar anchor to resolve
+	/// them against. The resulting value formats back into the crate's textual format via its
+	/// [`Display`](fmt::Display) implementation, so `parse(interval.to_string())` round-trips.
+	pub fn from_duration(duration: Duration) -> Self {
+		let sign = if duration < Duration::zero() { -1 } else { 1 };
+		let mut remaining = duration.num_seconds().abs();
+		let nanoseconds = duration.subsec_nanos().unsigned_abs() as i64;
+		let weeks = remaining / WEEK_SECONDS;
+		remaining %= WEEK_SECONDS;
+		let days = remaining / DAY_SECONDS;
+		remaining %= DAY_SECONDS;
+		let hours = remaining / HOUR_SECONDS;
+		remaining %= HOUR_SECONDS;
+		let minutes = remaining / MINUTE_SECONDS;
+		remaining %= MINUTE_SECONDS;
+		Self {
+			years: 0,
+			months: 0,
+			weeks: sign * weeks,
+			days: sign * days,
+			hours: sign * hours,
+			minutes: sign * minutes,
+			seconds: sign * remaining,
+			nanoseconds: sign * nanoseconds,
+			sign: 1,
+		}
+	}
+}
+
+/// Render an interval in the crate's textual format. Zero components are omitted; a leading `-`
+/// marks a negative interval. The default is the compact form (`5w3d1h30m30s`); the alternate flag
+/// (`{:#}`) selects the long form (`5 weeks 3 days 1 hour 30 minutes 30 seconds`).
+impl fmt::Display for Interval {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let long = f.alternate();
+		let s = self.sign as i64;
+		let components = [
+			(self.years * s, "y", "year", "years"),
+			(self.months * s, "mo", "month", "months"),
+			(self.weeks * s, "w", "week", "weeks"),
+			(self.days * s, "d", "day", "days"),
+			(self.hours * s, "h", "hour", "hours"),
+			(self.minutes * s, "m", "minute", "minutes"),
+			(self.seconds * s, "s", "second", "seconds"),
+		];
+		let nanoseconds = self.nanoseconds * s;
+
+		let mut running_negative = false;
+		let mut wrote_any = false;
+		for (index, &(value, compact, singular, plural)) in components.iter().enumerate() {
+			let has_fraction = index == components.len() - 1 && nanoseconds != 0;
+			if value == 0 && !has_fraction {
+				continue;
+			}
+			let negative = value < 0 || (value == 0 && nanoseconds < 0);
+			if wrote_any && long {
+				f.write_str(" ")?;
+			}
+			if negative != running_negative {
+				f.write_str("-")?;
+				running_negative = negative;
+			}
+			let magnitude = value.unsigned_abs();
+			if has_fraction {
+				let fraction = format!("{:09}", nanoseconds.unsigned_abs());
+				write!(f, "{}.{}", magnitude, fraction.trim_end_matches('0'))?;
+			} else {
+				write!(f, "{magnitude}")?;
+			}
+			if long {
+				let name = if magnitude == 1 && !has_fraction {
+					singular
+				} else {
+					plural
+				};
+				write!(f, " {name}")?;
+			} else {
+				f.write_str(compact)?;
+			}
+			wrote_any = true;
+		}
+
+		if !wrote_any {
+			f.write_str(if long { "0 seconds" } else { "0s" })?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::simple;
+
+	#[test]
+	fn compact() {
+		assert_eq!(
+			Interval::from_duration(Duration::seconds(3288630)).to_string(),
+			"5w3d1h30m30s"
+		);
+	}
+	#[test]
+	fn long() {
+		assert_eq!(
+			format!("{:#}", Interval::from_duration(Duration::seconds(3288630))),
+			"5 weeks 3 days 1 hour 30 minutes 30 seconds"
+		);
+	}
+	#[test]
+	fn omits_zero_components() {
+		assert_eq!(
+			Interval::from_duration(Duration::seconds(90)).to_string(),
+			"1m30s"
+		);
+	}
+	#[test]
+	fn negative() {
+		assert_eq!(
+			Interval::from_duration(Duration::seconds(-3283200)).to_string(),
+			"-5w3d"
+		);
+	}
+	#[test]
+	fn zero() {
+		assert_eq!(Interval::from_duration(Duration::seconds(0)).to_string(), "0s");
+	}
+	#[test]
+	fn sub_second() {
+		assert_eq!(
+			Interval::from_duration(Duration::milliseconds(100)).to_string(),
+			"0.1s"
+		);
+	}
+	#[test]
+	fn round_trips() {
+		let duration = Duration::seconds(3288630);
+		assert_eq!(
+			simple(&Interval::from_duration(duration).to_string()),
+			Ok(duration)
+		);
+	}
+}
@@ -14,21 +14,47 @@ pub use errors::ParseError;
 use parse_bytes::ParseBytes;
 
 mod errors;
+mod format;
 mod parse_bytes;
 mod time_units;
 
+/// A parsed interval, kept as its individual components so the calendar math (years and months)
+/// can be deferred until a reference date is supplied.
+///
+/// The fixed part (weeks down to the sub-second `nanoseconds`) resolves on its own, while `years`
+/// and `months` are inconstant: they only become a concrete [`Duration`] once anchored to a date,
+/// via [`Interval::to_duration`] or [`Interval::to_duration_from_now`]. The same parsed value can
+/// therefore be resolved against different dates.
+///
+/// Each component already carries its own sign (so `5 weeks -3 days` stores `days: -3`); `sign` is
+/// an overall multiplier applied to the whole interval when it is resolved.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Interval {
+	pub years: i64,
+	pub months: i64,
+	pub weeks: i64,
+	pub days: i64,
+	pub hours: i64,
+	pub minutes: i64,
+	pub seconds: i64,
+	/// Sub-second remainder, in nanoseconds.
+	pub nanoseconds: i64,
+	/// Overall sign applied to the whole interval when resolved: `1` keeps it as-is, `-1` negates it.
+	pub sign: i8,
+}
+
 /// Parse an interval like "15 days 12 hours". It can include weeks, days, hours, minutes and seconds. It can not include years or months.
 pub fn simple(interval: &str) -> Result<Duration, ParseError> {
-	parse_interval(interval, None)
+	parse(interval)?.resolve(None)
 }
 
 /// Parse an interval like "1 year 15 days". Years and months will be evaluated as offset from the specified date.
 ///
 /// It can include years, months, weeks, days, hours, minutes and seconds.
 ///
-/// If you don't already have a date, it may be more efficient to use [`parse_interval_with_lazy_date`], since it avoids constructing it if it doesn't end up needing it (because there were no years or months).
+/// If you don't already have a date, it may be more efficient to use [`with_lazy_date`], since it avoids constructing it if it doesn't end up needing it (because there were no years or months).
 pub fn with_date(interval: &str, date: DateTime<Utc>) -> Result<Duration, ParseError> {
-	parse_interval(interval, Some(Box::new(move || date)))
+	parse(interval)?.to_duration(date)
 }
 
 /// Parse an interval like "1 year 15 days". Years and months will be evaluated as offset from the date generated by the passed function.
@@ -38,9 +64,14 @@ pub fn with_date(interval: &str, date: DateTime<Utc>) -> Result<Duration, ParseE
 /// This avoids constructing the date if it doesn't end up needing it (because there were no years or months).
 pub fn with_lazy_date<D>(interval: &str, get_date: D) -> Result<Duration, ParseError>
 where
-	D: FnOnce() -> DateTime<Utc> + 'static,
+	D: FnOnce() -> DateTime<Utc>,
 {
-	parse_interval(interval, Some(Box::new(get_date)))
+	let parsed = parse(interval)?;
+	if parsed.years != 0 || parsed.months != 0 {
+		parsed.to_duration(get_date())
+	} else {
+		parsed.resolve(None)
+	}
 }
 
 /// Parse an interval like "1 year 15 days". Years and months will be evaluated as offset from the present (current system time).
@@ -50,32 +81,301 @@ pub fn with_now(interval: &str) -> Result<Duration, ParseError> {
 	with_lazy_date(interval, Utc::now)
 }
 
-/// Parse an interval like "1 year 15 days". The years and months are evaluated as offset from the generated date.
+/// Parse an interval into its [`Interval`] components, without resolving years or months against a date.
 ///
-/// If a date constructor is provided, it can include years and months. Either way it can include weeks, days, hours, minutes and seconds.
-fn parse_interval(
-	interval: &str,
-	mut get_date: Option<Box<dyn FnOnce() -> DateTime<Utc>>>,
-) -> Result<Duration, ParseError> {
-	static PATTERNS: OnceLock<[time_units::TimeUnit; 7]> = OnceLock::new();
-	let units = PATTERNS.get_or_init(|| time_units::UNITS.map(|unit| unit.compile()));
-
-	let allow_inconstant = get_date.is_some();
+/// Accepts both the human format ("1 year 15 days") and ISO 8601 durations ("P1Y15D"). The returned
+/// value defers the calendar math, so it can be turned into a [`Duration`] against any reference
+/// date with [`Interval::to_duration`].
+pub fn parse(interval: &str) -> Result<Interval, ParseError> {
+	let units = compiled_units();
 
-	let mut date = None;
 	let mut bytes = ParseBytes::from_str(interval);
-	let mut duration = Duration::seconds(0);
-	let mut offset_date = None;
-	let mut is_subtracting = false;
-	let mut unit_cursor = if allow_inconstant {
-		0
+	bytes.skip_spaces();
+	if bytes.is_empty() {
+		return Err(ParseError::Empty);
+	}
+	if bytes.peek() == Some(b'P') {
+		parse_iso8601(bytes, units)
 	} else {
-		2 // Skip years and months
-	};
+		parse_human(bytes, units)
+	}
+}
+
+/// Parse as many leading unit groups as possible, returning the resolved [`Duration`] together with
+/// the unconsumed tail, instead of erroring on trailing non-interval text.
+///
+/// This lets an interval be embedded in a larger string, e.g. `"5 days until launch"` yields
+/// `(5 days, "until launch")`. Like [`simple`], it has no reference date, so years and months are
+/// not allowed. Genuinely malformed numbers still error; a token that simply isn't an interval
+/// just ends the parse.
+///
+/// Input starting with `P` is parsed as one whole ISO 8601 duration: any text after it is an
+/// error, and the returned remainder is always empty.
+pub fn parse_interval_and_remainder(interval: &str) -> Result<(Duration, &str), ParseError> {
+	let units = compiled_units();
+
+	let mut bytes = ParseBytes::from_str(interval);
 	bytes.skip_spaces();
 	if bytes.is_empty() {
 		return Err(ParseError::Empty);
 	}
+	if bytes.peek() == Some(b'P') {
+		// An ISO 8601 duration is parsed as the whole input; trailing text is an error, not a remainder.
+		let parsed = parse_iso8601(bytes, units)?;
+		return Ok((parsed.resolve(None)?, ""));
+	}
+	let (parsed, remainder) = parse_human_with_remainder(bytes, units)?;
+	Ok((parsed.resolve(None)?, remainder))
+}
+
+/// The lazily compiled, process-wide unit patterns shared by the free functions.
+fn compiled_units() -> &'static [time_units::TimeUnit; 7] {
+	static PATTERNS: OnceLock<[time_units::TimeUnit; 7]> = OnceLock::new();
+	PATTERNS.get_or_init(|| time_units::UNITS.map(|unit| unit.compile()))
+}
+
+/// A parser with a customizable unit vocabulary.
+///
+/// The free functions ([`parse`], [`simple`], [`with_date`]...) use a fixed English vocabulary. To
+/// parse other languages, or to add short aliases, build an `IntervalParser` with your own per-unit
+/// patterns and call [`IntervalParser::parse`] on it. The patterns are compiled once when the parser
+/// is built and reused across calls.
+///
+/// The strict descending-order sequencing (years, months, weeks, days, hours, minutes, seconds) is
+/// unchanged; only the patterns that name each unit differ.
+///
+/// ```
+/// let parser = parse_interval::IntervalParser::builder()
+///     .days("jours?|j")
+///     .build()
+///     .unwrap();
+/// assert_eq!(parser.parse("3 jours").unwrap().days, 3);
+/// ```
+#[derive(Debug)]
+pub struct IntervalParser {
+	units: [time_units::TimeUnit; 7],
+}
+
+impl IntervalParser {
+	/// Start building a parser from the default English patterns. Override any unit before calling
+	/// [`IntervalParserBuilder::build`].
+	pub fn builder() -> IntervalParserBuilder {
+		IntervalParserBuilder {
+			patterns: time_units::UNITS.map(|unit| unit.pattern.to_owned()),
+		}
+	}
+
+	/// Parse an interval using this parser's vocabulary into its [`Interval`] components.
+	pub fn parse(&self, interval: &str) -> Result<Interval, ParseError> {
+		let mut bytes = ParseBytes::from_str(interval);
+		bytes.skip_spaces();
+		if bytes.is_empty() {
+			return Err(ParseError::Empty);
+		}
+		if bytes.peek() == Some(b'P') {
+			parse_iso8601(bytes, &self.units)
+		} else {
+			parse_human(bytes, &self.units)
+		}
+	}
+}
+
+/// Builder for an [`IntervalParser`]. Each setter replaces the pattern body (a regex, matched
+/// case-insensitively and anchored to the start of a unit) for one unit.
+#[derive(Debug, Clone)]
+pub struct IntervalParserBuilder {
+	patterns: [String; 7],
+}
+
+impl IntervalParserBuilder {
+	/// Set the pattern matching the years unit.
+	pub fn years(mut self, pattern: impl Into<String>) -> Self {
+		self.patterns[0] = pattern.into();
+		self
+	}
+	/// Set the pattern matching the months unit.
+	pub fn months(mut self, pattern: impl Into<String>) -> Self {
+		self.patterns[1] = pattern.into();
+		self
+	}
+	/// Set the pattern matching the weeks unit.
+	pub fn weeks(mut self, pattern: impl Into<String>) -> Self {
+		self.patterns[2] = pattern.into();
+		self
+	}
+	/// Set the pattern matching the days unit.
+	pub fn days(mut self, pattern: impl Into<String>) -> Self {
+		self.patterns[3] = pattern.into();
+		self
+	}
+	/// Set the pattern matching the hours unit.
+	pub fn hours(mut self, pattern: impl Into<String>) -> Self {
+		self.patterns[4] = pattern.into();
+		self
+	}
+	/// Set the pattern matching the minutes unit.
+	pub fn minutes(mut self, pattern: impl Into<String>) -> Self {
+		self.patterns[5] = pattern.into();
+		self
+	}
+	/// Set the pattern matching the seconds unit.
+	pub fn seconds(mut self, pattern: impl Into<String>) -> Self {
+		self.patterns[6] = pattern.into();
+		self
+	}
+
+	/// Compile the patterns into an [`IntervalParser`]. Fails if any pattern is not a valid regex.
+	pub fn build(self) -> Result<IntervalParser, regex::Error> {
+		let seconds = time_units::UNITS.map(|unit| unit.seconds);
+		let units = [
+			time_units::TimeUnit::from_pattern(seconds[0], &self.patterns[0])?,
+			time_units::TimeUnit::from_pattern(seconds[1], &self.patterns[1])?,
+			time_units::TimeUnit::from_pattern(seconds[2], &self.patterns[2])?,
+			time_units::TimeUnit::from_pattern(seconds[3], &self.patterns[3])?,
+			time_units::TimeUnit::from_pattern(seconds[4], &self.patterns[4])?,
+			time_units::TimeUnit::from_pattern(seconds[5], &self.patterns[5])?,
+			time_units::TimeUnit::from_pattern(seconds[6], &self.patterns[6])?,
+		];
+		Ok(IntervalParser { units })
+	}
+}
+
+impl Interval {
+	/// An interval with all components zeroed and a positive sign.
+	fn zero() -> Self {
+		Self {
+			years: 0,
+			months: 0,
+			weeks: 0,
+			days: 0,
+			hours: 0,
+			minutes: 0,
+			seconds: 0,
+			nanoseconds: 0,
+			sign: 1,
+		}
+	}
+
+	/// Resolve the interval into a [`Duration`], evaluating years and months as an offset from `date`.
+	pub fn to_duration(&self, date: DateTime<Utc>) -> Result<Duration, ParseError> {
+		self.resolve(Some(date))
+	}
+
+	/// Resolve the interval into a [`Duration`], evaluating years and months as an offset from the present (current system time).
+	pub fn to_duration_from_now(&self) -> Result<Duration, ParseError> {
+		self.resolve(Some(Utc::now()))
+	}
+
+	/// Resolve the interval into a [`Duration`]. Years and months are applied to `date` in turn; if
+	/// they are present but no date is available, this fails with [`ParseError::InconstantUnitWithoutDate`].
+	fn resolve(&self, date: Option<DateTime<Utc>>) -> Result<Duration, ParseError> {
+		let mut duration = Duration::seconds(0);
+
+		if self.years != 0 || self.months != 0 {
+			let date = date.ok_or(ParseError::InconstantUnitWithoutDate)?;
+			let mut offset = date;
+			if self.years != 0 {
+				let months = self.years.checked_mul(12).ok_or(ParseError::NumberOutOfRange)?;
+				offset = offset_months(offset, months)?;
+			}
+			if self.months != 0 {
+				offset = offset_months(offset, self.months)?;
+			}
+			duration = duration
+				.checked_add(&(offset - date))
+				.ok_or(ParseError::NumberOutOfRange)?;
+		}
+
+		for &(count, unit_seconds) in &[
+			(self.weeks, 7 * 24 * 60 * 60i64),
+			(self.days, 24 * 60 * 60),
+			(self.hours, 60 * 60),
+			(self.minutes, 60),
+			(self.seconds, 1),
+		] {
+			let part = count
+				.checked_mul(unit_seconds)
+				.map(Duration::seconds)
+				.ok_or(ParseError::NumberOutOfRange)?;
+			duration = duration.checked_add(&part).ok_or(ParseError::NumberOutOfRange)?;
+		}
+		if self.nanoseconds != 0 {
+			duration = duration
+				.checked_add(&Duration::nanoseconds(self.nanoseconds))
+				.ok_or(ParseError::NumberOutOfRange)?;
+		}
+
+		if self.sign < 0 {
+			duration = Duration::seconds(0)
+				.checked_sub(&duration)
+				.ok_or(ParseError::NumberOutOfRange)?;
+		}
+		Ok(duration)
+	}
+
+	/// Fold one parsed component into the interval, picking the right field by unit index (years,
+	/// months, weeks, days, hours, minutes, seconds) and carrying its sign. Fractions on years or
+	/// months are rejected; fractions on the fixed units accumulate into `seconds` and `nanoseconds`.
+	fn add_component(
+		&mut self,
+		unit_index: usize,
+		number: i64,
+		fraction: f64,
+		unit_seconds: i64,
+		is_subtracting: bool,
+	) -> Result<(), ParseError> {
+		if unit_index <= 1 && fraction > 0.0 {
+			return Err(ParseError::InconstantUnitWithFraction);
+		}
+		let sign: i64 = if is_subtracting { -1 } else { 1 };
+		let value = number.checked_mul(sign).ok_or(ParseError::NumberOutOfRange)?;
+		let field = match unit_index {
+			0 => &mut self.years,
+			1 => &mut self.months,
+			2 => &mut self.weeks,
+			3 => &mut self.days,
+			4 => &mut self.hours,
+			5 => &mut self.minutes,
+			_ => &mut self.seconds,
+		};
+		*field = field.checked_add(value).ok_or(ParseError::NumberOutOfRange)?;
+		if unit_index > 1 && fraction > 0.0 {
+			// Split the fractional part into whole seconds plus a nanosecond remainder, so sub-second
+			// precision survives (`0.1s` is 100ms, not zero).
+			let fraction_seconds = fraction * unit_seconds as f64;
+			let whole = fraction_seconds.trunc() as i64 * sign;
+			let nanoseconds = ((fraction_seconds.fract()) * 1e9).round() as i64 * sign;
+			self.seconds = self.seconds.checked_add(whole).ok_or(ParseError::NumberOutOfRange)?;
+			self.nanoseconds = self
+				.nanoseconds
+				.checked_add(nanoseconds)
+				.ok_or(ParseError::NumberOutOfRange)?;
+			// Carry whole seconds out of the nanosecond accumulator so additions and subtractions
+			// stay normalized and symmetric.
+			let carry = self.nanoseconds / 1_000_000_000;
+			self.nanoseconds -= carry * 1_000_000_000;
+			self.seconds = self.seconds.checked_add(carry).ok_or(ParseError::NumberOutOfRange)?;
+		}
+		Ok(())
+	}
+}
+
+/// Apply `months` (which may be negative) to `date`, as a calendar offset.
+fn offset_months(date: DateTime<Utc>, months: i64) -> Result<DateTime<Utc>, ParseError> {
+	let magnitude = Months::new(months.unsigned_abs().try_into()?);
+	if months < 0 {
+		date.checked_sub_months(magnitude)
+	} else {
+		date.checked_add_months(magnitude)
+	}
+	.ok_or(ParseError::DateOutOfRange)
+}
+
+/// Parse the human format ("1 year 15 days") into an [`Interval`].
+fn parse_human(mut bytes: ParseBytes, units: &[time_units::TimeUnit; 7]) -> Result<Interval, ParseError> {
+	let mut interval = Interval::zero();
+	let mut is_subtracting = false;
+	let mut unit_cursor = 0;
 	'outer: while !bytes.is_empty() {
 		if bytes.parse_minus() {
 			is_subtracting = !is_subtracting;
@@ -86,83 +386,125 @@ fn parse_interval(
 		for (unit_index, unit) in units.iter().enumerate().skip(unit_cursor) {
 			unit_cursor += 1;
 			if bytes.parse_regex(&unit.regex) {
-				match unit_index {
-					// Years
-					0 => {
-						if fraction > 0.0 {
-							return Err(ParseError::InconstantUnitWithFraction);
-						}
-						let date =
-							date.get_or_insert_with(|| get_date.take().map(|f| f()).unwrap());
-						let offset_date = offset_date.get_or_insert(*date);
-						let months = Months::new(
-							number
-								.checked_mul(12)
-								.ok_or(ParseError::NumberOutOfRange)?
-								.try_into()?,
-						);
-						*offset_date = if is_subtracting {
-							offset_date.checked_sub_months(months)
-						} else {
-							offset_date.checked_add_months(months)
-						}
-						.ok_or(ParseError::DateOutOfRange)?;
-					}
-					// Months
-					1 => {
-						if fraction > 0.0 {
-							return Err(ParseError::InconstantUnitWithFraction);
-						}
-						let date =
-							date.get_or_insert_with(|| get_date.take().map(|f| f()).unwrap());
-						let offset_date = offset_date.get_or_insert(*date);
-						let months = Months::new(number.try_into()?);
-						*offset_date = if is_subtracting {
-							offset_date.checked_sub_months(months)
-						} else {
-							offset_date.checked_add_months(months)
-						}
-						.ok_or(ParseError::DateOutOfRange)?;
-					}
-					// Other
-					_ => {
-						let fraction_part =
-							Duration::seconds((fraction * unit.seconds as f32) as i64);
-						duration = number
-							.checked_mul(unit.seconds)
-							.map(Duration::seconds)
-							.and_then(|d| {
-								if is_subtracting {
-									duration
-										.checked_sub(&d)
-										.and_then(|d| d.checked_sub(&fraction_part))
-								} else {
-									duration
-										.checked_add(&d)
-										.and_then(|d| d.checked_add(&fraction_part))
-								}
-							})
-							.ok_or(ParseError::NumberOutOfRange)?;
-					}
-				}
+				interval.add_component(unit_index, number, fraction, unit.seconds, is_subtracting)?;
 				bytes.skip_spaces();
 				continue 'outer;
 			}
 		}
-		return Err(ParseError::diagnose_unit_error(
-			&bytes,
-			units,
-			unit_cursor,
-			allow_inconstant,
-		));
+		return Err(ParseError::diagnose_unit_error(&bytes, units, unit_cursor));
 	}
+	Ok(interval)
+}
 
-	if let (Some(date), Some(offset_date)) = (date, offset_date) {
-		duration = duration
-			.checked_add(&(offset_date - date))
-			.ok_or(ParseError::NumberOutOfRange)?;
+/// Parse the human format like [`parse_human`], but stop at the first token that is not an interval
+/// unit group and report where that happens, rather than erroring.
+///
+/// Returns the accumulated [`Interval`] and the unconsumed tail of the input. Malformed
+/// numbers that have already begun consuming still error.
+fn parse_human_with_remainder<'l>(
+	mut bytes: ParseBytes<'l>,
+	units: &[time_units::TimeUnit; 7],
+) -> Result<(Interval, &'l str), ParseError> {
+	let mut interval = Interval::zero();
+	let mut is_subtracting = false;
+	let mut unit_cursor = 0;
+	'outer: while !bytes.is_empty() {
+		let group_start = bytes.clone();
+		if bytes.parse_minus() {
+			is_subtracting = !is_subtracting;
+			bytes.skip_spaces();
+		}
+		let (number, fraction) = match bytes.parse_number() {
+			Ok(number) => number,
+			// No number where a group would begin: the rest is remainder, not an error.
+			Err(ParseError::NoNumber(_)) => {
+				bytes = group_start;
+				break 'outer;
+			}
+			Err(error) => return Err(error),
+		};
+		bytes.skip_spaces();
+		for (unit_index, unit) in units.iter().enumerate().skip(unit_cursor) {
+			unit_cursor += 1;
+			if bytes.parse_regex(&unit.regex) {
+				interval.add_component(unit_index, number, fraction, unit.seconds, is_subtracting)?;
+				bytes.skip_spaces();
+				continue 'outer;
+			}
+		}
+		// A number that is not followed by a (remaining) unit: rewind over it and stop.
+		bytes = group_start;
+		break 'outer;
+	}
+	Ok((interval, bytes.remainder()))
+}
+
+/// Parse an ISO 8601 duration like `P1Y2M10DT2H30M` or `PT15M` into an [`Interval`].
+///
+/// The leading `P` has not yet been consumed. The date section (`Y`, `M`onths, `W`, `D`) precedes
+/// an optional `T` marker, after which the time section (`H`, `M`inutes, `S`) follows. A `M`
+/// designator therefore means months before the `T` and minutes after it. A fraction is accepted
+/// on any week, day, hour, minute or second component, not only the smallest present one; years
+/// and months with a fraction are rejected. Years and months are kept as calendar components, so
+/// resolving them still needs a date.
+fn parse_iso8601(mut bytes: ParseBytes, units: &[time_units::TimeUnit; 7]) -> Result<Interval, ParseError> {
+	bytes.parse_byte(b'P');
+
+	let mut interval = Interval::zero();
+	let mut any_component = false;
+
+	// Date section: years, months, weeks, days (unit indices 0..=3), then an optional `T`.
+	let mut unit_cursor = 0;
+	while !bytes.is_empty() && bytes.peek() != Some(b'T') {
+		let position = bytes.offset();
+		let (number, fraction) = bytes.parse_number()?;
+		let unit_index = match bytes.parse_designator() {
+			Some(b'Y') => 0,
+			Some(b'M') => 1,
+			Some(b'W') => 2,
+			Some(b'D') => 3,
+			_ => return Err(ParseError::NoUnit(position)),
+		};
+		if unit_index < unit_cursor {
+			return Err(ParseError::UnitOutOfSequence(position));
+		}
+		unit_cursor = unit_index + 1;
+		interval.add_component(unit_index, number, fraction, units[unit_index].seconds, false)?;
+		any_component = true;
+	}
+
+	// Time section: hours, minutes, seconds (unit indices 4..=6).
+	if bytes.parse_byte(b'T') {
+		let mut unit_cursor = 4;
+		let mut any_time_component = false;
+		while !bytes.is_empty() {
+			let position = bytes.offset();
+			let (number, fraction) = bytes.parse_number()?;
+			let unit_index = match bytes.parse_designator() {
+				Some(b'H') => 4,
+				Some(b'M') => 5,
+				Some(b'S') => 6,
+				_ => return Err(ParseError::NoUnit(position)),
+			};
+			if unit_index < unit_cursor {
+				return Err(ParseError::UnitOutOfSequence(position));
+			}
+			unit_cursor = unit_index + 1;
+			interval.add_component(unit_index, number, fraction, units[unit_index].seconds, false)?;
+			any_time_component = true;
+		}
+		// A `T` marker must be followed by at least one time component.
+		if !any_time_component {
+			return Err(ParseError::NoNumber(bytes.offset()));
+		}
+		any_component = true;
 	}
-	Ok(duration)
+
+	// A bare `P` with no components at all is not a valid duration.
+	if !any_component {
+		return Err(ParseError::NoNumber(bytes.offset()));
+	}
+	Ok(interval)
 }
 
 const _PATTERN: &str = r"^(?:(?:(-) ?)?(\d+) ?y(?:ears?)?\s?)?(?:(?:(-) ?)?(\d+) ?mo(?:nths?)?\s?)?(?:(?:(-) ?)?(\d+(?:\.\d+)?|\.\d+) ?w(?:eeks?)?\s?)?(?:(?:(-) ?)?(\d+(?:\.\d+)?|\.\d+) ?d(?:ays?)?\s?)?(?:(?:(-) ?)?(\d+(?:\.\d+)?|\.\d+) ?h(?:(?:ou)?rs?)?\s?)?(?:(?:(-) ?)?(\d+(?:\.\d+)?|\.\d+) ?m(?:in(?:ute)?s?)?\s?)?(?:(?:(-) ?)?(\d+(?:\.\d+)?|\.\d+) ?s(?:ec(?:ond)?s?)?\s?)?$/i";
@@ -216,11 +558,12 @@ mod tests {
 			Ok(Duration::seconds(531211))
 		);
 	}
-	/// I don't have any particular rounding behaviour in mind, but if it changes, I'd like to know.
+	/// Fractions accumulate into nanoseconds, so sub-second precision is preserved.
 	#[test]
 	fn fraction_rounding() {
-		assert_eq!(simple("0.1s"), Ok(Duration::seconds(0)));
-		assert_eq!(simple("0.017m"), Ok(Duration::seconds(1)));
+		assert_eq!(simple("0.1s"), Ok(Duration::milliseconds(100)));
+		assert_eq!(simple("0.017m"), Ok(Duration::milliseconds(1020)));
+		assert_eq!(simple("1.5h"), Ok(Duration::minutes(90)));
 	}
 	#[test]
 	fn invalid_fraction() {
@@ -325,6 +668,121 @@ mod tests {
 		);
 	}
 	#[test]
+	fn iso_time_only() {
+		assert_eq!(simple("PT15M"), Ok(Duration::seconds(900)));
+	}
+	#[test]
+	fn iso_date_and_time() {
+		assert_eq!(simple("P10DT2H30M"), Ok(Duration::seconds(873000)));
+	}
+	#[test]
+	fn iso_weeks_and_days() {
+		assert_eq!(simple("P1W3D"), Ok(Duration::seconds(864000)));
+	}
+	#[test]
+	fn iso_fraction() {
+		assert_eq!(simple("PT1.5H"), Ok(Duration::seconds(5400)));
+	}
+	#[test]
+	fn iso_month_equals_human_month() {
+		assert_eq!(
+			with_date("P1M", date_year_month_day(2000, 2, 1)),
+			Ok(Duration::days(29))
+		);
+	}
+	#[test]
+	fn iso_month_then_minute() {
+		assert_eq!(
+			with_date("P1MT1M", date_year_month_day(2000, 2, 1)),
+			Ok(Duration::days(29) + Duration::minutes(1))
+		);
+	}
+	#[test]
+	fn iso_years_without_date() {
+		assert_eq!(simple("P1Y"), Err(ParseError::InconstantUnitWithoutDate));
+	}
+	#[test]
+	fn iso_bare_p() {
+		assert_eq!(simple("P"), Err(ParseError::NoNumber(1)));
+	}
+	#[test]
+	fn iso_dangling_t() {
+		assert_eq!(simple("PT"), Err(ParseError::NoNumber(2)));
+	}
+	#[test]
+	fn iso_out_of_sequence() {
+		assert_eq!(simple("P3D1W"), Err(ParseError::UnitOutOfSequence(3)));
+	}
+	#[test]
+	fn parse_keeps_components() {
+		assert_eq!(
+			parse("5 weeks 3 days"),
+			Ok(Interval {
+				years: 0,
+				months: 0,
+				weeks: 5,
+				days: 3,
+				hours: 0,
+				minutes: 0,
+				seconds: 0,
+				nanoseconds: 0,
+				sign: 1,
+			})
+		);
+	}
+	#[test]
+	fn interval_resolves_against_different_dates() {
+		let parsed = parse("1 month").unwrap();
+		assert_eq!(
+			parsed.to_duration(date_year_month_day(2000, 2, 1)),
+			Ok(Duration::days(29))
+		);
+		assert_eq!(
+			parsed.to_duration(date_year_month_day(2001, 2, 1)),
+			Ok(Duration::days(28))
+		);
+	}
+	#[test]
+	fn parse_and_remainder() {
+		assert_eq!(
+			parse_interval_and_remainder("5 days until launch"),
+			Ok((Duration::days(5), "until launch"))
+		);
+	}
+	#[test]
+	fn parse_and_remainder_fully_consumed() {
+		assert_eq!(
+			parse_interval_and_remainder("5 days"),
+			Ok((Duration::days(5), ""))
+		);
+	}
+	#[test]
+	fn parse_and_remainder_stops_at_garbage() {
+		assert_eq!(
+			parse_interval_and_remainder("3 weeks 2 apples"),
+			Ok((Duration::weeks(3), "2 apples"))
+		);
+	}
+	#[test]
+	fn localized_vocabulary() {
+		let parser = IntervalParser::builder()
+			.weeks("semaines?|sem")
+			.days("jours?|j")
+			.build()
+			.unwrap();
+		let parsed = parser.parse("2 semaines 3 jours").unwrap();
+		assert_eq!((parsed.weeks, parsed.days), (2, 3));
+	}
+	#[test]
+	fn custom_parser_matches_default() {
+		let parser = IntervalParser::builder().build().unwrap();
+		assert_eq!(parser.parse("5 weeks 3 days"), parse("5 weeks 3 days"));
+	}
+	#[test]
+	fn invalid_custom_pattern() {
+		assert!(IntervalParser::builder().days("(unclosed").build().is_err());
+	}
+	#[test]
 	fn doc_examples() {
 		let duration = self::with_now("2 days 15 hours 15 mins");
 		assert_eq!(duration, Ok(chrono::Duration::seconds(227700)));
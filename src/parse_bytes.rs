@@ -23,6 +23,30 @@ impl<'l> ParseBytes<'l> {
 			false
 		}
 	}
+	/// Peek at the next byte without consuming it.
+	pub(crate) fn peek(&self) -> Option<u8> {
+		self.shrinking.first().copied()
+	}
+	/// Consume the next byte if it equals `byte`.
+	pub(crate) fn parse_byte(&mut self, byte: u8) -> bool {
+		if self.shrinking.first() == Some(&byte) {
+			self.shrinking = &self.shrinking[1..];
+			true
+		} else {
+			false
+		}
+	}
+	/// Consume a single ASCII letter used as an ISO 8601 designator, returned upper-cased.
+	pub(crate) fn parse_designator(&mut self) -> Option<u8> {
+		match self.shrinking.first() {
+			Some(byte) if byte.is_ascii_alphabetic() => {
+				let designator = byte.to_ascii_uppercase();
+				self.shrinking = &self.shrinking[1..];
+				Some(designator)
+			}
+			_ => None,
+		}
+	}
 	pub(crate) fn parse_minus(&mut self) -> bool {
 		if self.shrinking.first() == Some(&b'-') {
 			self.shrinking = &self.shrinking[1..];
@@ -34,9 +58,9 @@ impl<'l> ParseBytes<'l> {
 	/// Parse digits into a number until a non-digit is encountered.
 	///
 	/// Will return an error on empty input or on overflow.
-	pub(crate) fn parse_number(&mut self) -> Result<(i64, f32), ParseError> {
+	pub(crate) fn parse_number(&mut self) -> Result<(i64, f64), ParseError> {
 		let mut number: i64 = 0;
-		let mut fraction: f32 = 0.0;
+		let mut fraction: f64 = 0.0;
 		let mut fractional_position = 0;
 		let mut highest_index = 0;
 		#[allow(clippy::manual_is_ascii_check)]
@@ -56,7 +80,7 @@ impl<'l> ParseBytes<'l> {
 					.and_then(|n| n.checked_add((byte - b'0') as i64))
 					.ok_or(ParseError::NumberOutOfRange)?;
 			} else {
-				fraction += (byte - b'0') as f32 / 10.0f32.powi(fractional_position);
+				fraction += (byte - b'0') as f64 / 10.0f64.powi(fractional_position);
 				fractional_position += 1;
 			}
 			highest_index += 1;
@@ -72,7 +96,8 @@ impl<'l> ParseBytes<'l> {
 		if let Some(index) = self.shrinking.iter().position(|&byte| byte != b' ') {
 			self.shrinking = &self.shrinking[index..];
 		} else {
-			self.shrinking = &[];
+			// Keep the pointer derived from `original` so `offset`/`remainder` stay valid.
+			self.shrinking = &self.shrinking[self.shrinking.len()..];
 		}
 	}
 	pub(crate) fn is_empty(&self) -> bool {
@@ -81,4 +106,8 @@ impl<'l> ParseBytes<'l> {
 	pub(crate) fn offset(&self) -> usize {
 		self.shrinking.as_ptr() as usize - self.original.as_bytes().as_ptr() as usize
 	}
+	/// The not-yet-consumed tail of the original string.
+	pub(crate) fn remainder(&self) -> &'l str {
+		&self.original[self.offset()..]
+	}
 }
@@ -10,48 +10,56 @@ pub(crate) struct TimeUnitRaw {
 pub(crate) const UNITS: [TimeUnitRaw; 7] = [
 	TimeUnitRaw {
 		seconds: 365 * 7 * 24 * 60 * 60, // Not used
-		pattern: "^y(?:ears?)?",
+		pattern: "y(?:ears?)?",
 	},
 	TimeUnitRaw {
 		seconds: 30 * 7 * 24 * 60 * 60, // Not used
-		pattern: "^mo(?:nths?)?",
+		pattern: "mo(?:nths?)?",
 	},
 	TimeUnitRaw {
 		seconds: 7 * 24 * 60 * 60,
-		pattern: "^w(?:eeks?)?",
+		pattern: "w(?:eeks?)?",
 	},
 	TimeUnitRaw {
 		seconds: 24 * 60 * 60,
-		pattern: "^d(?:ays?)?",
+		pattern: "d(?:ays?)?",
 	},
 	TimeUnitRaw {
 		seconds: 60 * 60,
-		pattern: "^h(?:(?:ou)?rs?)?",
+		pattern: "h(?:(?:ou)?rs?)?",
 	},
 	TimeUnitRaw {
 		seconds: 60,
-		pattern: "^m(?:in(?:ute)?s?)?",
+		pattern: "m(?:in(?:ute)?s?)?",
 	},
 	TimeUnitRaw {
 		seconds: 1,
-		pattern: "^s(?:ec(?:ond)?s?)?",
+		pattern: "s(?:ec(?:ond)?s?)?",
 	},
 ];
 
 impl TimeUnitRaw {
 	pub(crate) fn compile(&self) -> TimeUnit {
-		let regex = RegexBuilder::new(self.pattern)
-			.case_insensitive(true)
-			.build()
-			.unwrap();
-		TimeUnit {
-			seconds: self.seconds,
-			regex,
-		}
+		TimeUnit::from_pattern(self.seconds, self.pattern)
+			.expect("built-in unit pattern should always compile")
 	}
 }
 
+#[derive(Debug)]
 pub(crate) struct TimeUnit {
 	pub(crate) seconds: i64,
 	pub(crate) regex: Regex,
 }
+
+impl TimeUnit {
+	/// Compile a unit pattern, anchored to the start of the input and matched case-insensitively.
+	///
+	/// The pattern is the unit's body only (e.g. `"w(?:eeks?)?"` or a localized `"semaines?|sem"`);
+	/// anchoring is added here so callers can supply their own vocabulary without worrying about it.
+	pub(crate) fn from_pattern(seconds: i64, pattern: &str) -> Result<Self, regex::Error> {
+		let regex = RegexBuilder::new(&format!("^(?:{pattern})"))
+			.case_insensitive(true)
+			.build()?;
+		Ok(Self { seconds, regex })
+	}
+}